@@ -1,4 +1,5 @@
 use std::collections::VecDeque;
+use std::ops::Range;
 
 use arrow::array::Array;
 use arrow::bitmap::MutableBitmap;
@@ -17,6 +18,17 @@ pub trait Nested: std::fmt::Debug + Send + Sync {
 
     fn push(&mut self, length: i64, is_valid: bool);
 
+    /// Pushes `n` rows at once, equivalent to calling [`Nested::push`] `n`
+    /// times with `length` starting at `start` and increasing by `step` each
+    /// time. Used to apply a constant-valued run of rep/def levels in one
+    /// shot; implementations should override this with a bulk append where
+    /// possible.
+    fn push_n(&mut self, start: i64, step: i64, is_valid: bool, n: usize) {
+        for i in 0..n {
+            self.push(start + step * i as i64, is_valid);
+        }
+    }
+
     fn is_nullable(&self) -> bool;
 
     fn is_repeated(&self) -> bool {
@@ -65,6 +77,10 @@ impl Nested for NestedPrimitive {
         self.length += 1
     }
 
+    fn push_n(&mut self, _start: i64, _step: i64, _is_valid: bool, n: usize) {
+        self.length += n;
+    }
+
     fn len(&self) -> usize {
         self.length
     }
@@ -96,7 +112,6 @@ impl Nested for NestedOptional {
     }
 
     fn is_required(&self) -> bool {
-        // it may be for FixedSizeList
         false
     }
 
@@ -105,6 +120,11 @@ impl Nested for NestedOptional {
         self.validity.push(is_valid);
     }
 
+    fn push_n(&mut self, start: i64, step: i64, is_valid: bool, n: usize) {
+        self.offsets.extend((0..n as i64).map(|i| start + step * i));
+        self.validity.extend_constant(n, is_valid);
+    }
+
     fn len(&self) -> usize {
         self.offsets.len()
     }
@@ -142,7 +162,6 @@ impl Nested for NestedValid {
     }
 
     fn is_required(&self) -> bool {
-        // it may be for FixedSizeList
         false
     }
 
@@ -150,6 +169,10 @@ impl Nested for NestedValid {
         self.offsets.push(value);
     }
 
+    fn push_n(&mut self, start: i64, step: i64, _is_valid: bool, n: usize) {
+        self.offsets.extend((0..n as i64).map(|i| start + step * i));
+    }
+
     fn len(&self) -> usize {
         self.offsets.len()
     }
@@ -166,6 +189,65 @@ impl NestedValid {
     }
 }
 
+#[derive(Debug)]
+pub struct NestedFixedSize {
+    width: usize,
+    validity: Option<MutableBitmap>,
+    length: usize,
+}
+
+impl NestedFixedSize {
+    pub fn new(is_nullable: bool, width: usize, capacity: usize) -> Self {
+        Self {
+            width,
+            validity: is_nullable.then(|| MutableBitmap::with_capacity(capacity)),
+            length: 0,
+        }
+    }
+}
+
+impl Nested for NestedFixedSize {
+    fn inner(&mut self) -> (Vec<i64>, Option<MutableBitmap>) {
+        // no offsets: the stride is constant, so the child length is always
+        // `self.len() * self.width`.
+        (Default::default(), self.validity.take())
+    }
+
+    fn is_nullable(&self) -> bool {
+        self.validity.is_some()
+    }
+
+    fn is_repeated(&self) -> bool {
+        true
+    }
+
+    fn is_required(&self) -> bool {
+        false
+    }
+
+    fn push(&mut self, _value: i64, is_valid: bool) {
+        if let Some(validity) = &mut self.validity {
+            validity.push(is_valid);
+        }
+        self.length += 1;
+    }
+
+    fn push_n(&mut self, _start: i64, _step: i64, is_valid: bool, n: usize) {
+        if let Some(validity) = &mut self.validity {
+            validity.extend_constant(n, is_valid);
+        }
+        self.length += n;
+    }
+
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn num_values(&self) -> usize {
+        self.length * self.width
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct NestedStructValid {
     length: usize,
@@ -194,6 +276,10 @@ impl Nested for NestedStructValid {
         self.length += 1;
     }
 
+    fn push_n(&mut self, _start: i64, _step: i64, _is_valid: bool, n: usize) {
+        self.length += n;
+    }
+
     fn len(&self) -> usize {
         self.length
     }
@@ -233,6 +319,10 @@ impl Nested for NestedStruct {
         self.validity.push(is_valid)
     }
 
+    fn push_n(&mut self, _start: i64, _step: i64, is_valid: bool, n: usize) {
+        self.validity.extend_constant(n, is_valid);
+    }
+
     fn len(&self) -> usize {
         self.validity.len()
     }
@@ -264,6 +354,41 @@ pub(super) trait NestedDecoder<'a> {
     ) -> PolarsResult<()>;
     fn push_null(&self, decoded: &mut Self::DecodedState);
 
+    /// Advances `state` past one value without materializing it anywhere.
+    /// Used to keep the value stream aligned when a row is dropped by a
+    /// [`RowSelection`] pushdown.
+    fn skip_valid(&self, state: &mut Self::State) -> PolarsResult<()>;
+
+    /// Batched form of [`Self::push_valid`], called once for a whole run of
+    /// `n` values sharing the same (rep, def) levels. The default loops over
+    /// `push_valid`; override it to decode `n` values in one shot.
+    fn push_valid_n(
+        &self,
+        state: &mut Self::State,
+        decoded: &mut Self::DecodedState,
+        n: usize,
+    ) -> PolarsResult<()> {
+        for _ in 0..n {
+            self.push_valid(state, decoded)?;
+        }
+        Ok(())
+    }
+
+    /// Batched form of [`Self::push_null`]. See [`Self::push_valid_n`].
+    fn push_null_n(&self, decoded: &mut Self::DecodedState, n: usize) {
+        for _ in 0..n {
+            self.push_null(decoded);
+        }
+    }
+
+    /// Batched form of [`Self::skip_valid`]. See [`Self::push_valid_n`].
+    fn skip_valid_n(&self, state: &mut Self::State, n: usize) -> PolarsResult<()> {
+        for _ in 0..n {
+            self.skip_valid(state)?;
+        }
+        Ok(())
+    }
+
     fn deserialize_dict(&self, page: &DictPage) -> Self::Dictionary;
 }
 
@@ -277,6 +402,8 @@ pub enum InitNested {
     List(bool),
     /// Struct data types
     Struct(bool),
+    /// FixedSizeList data types (nullable, width)
+    FixedSizeList(bool, usize),
 }
 
 /// Initialize [`NestedState`] from `&[InitNested]`.
@@ -301,6 +428,9 @@ pub fn init_nested(init: &[InitNested], capacity: usize) -> NestedState {
                     Box::new(NestedStructValid::new()) as Box<dyn Nested>
                 }
             },
+            InitNested::FixedSizeList(is_nullable, width) => {
+                Box::new(NestedFixedSize::new(*is_nullable, *width, capacity)) as Box<dyn Nested>
+            },
         })
         .collect();
     NestedState::new(container)
@@ -331,6 +461,36 @@ impl<'a> NestedPage<'a> {
     pub fn len(&self) -> usize {
         self.iter.size_hint().0
     }
+
+    /// Peeks the `(rep, def)` pair of the next value without consuming it.
+    fn peek(&mut self) -> Option<(u32, u32)> {
+        self.iter.peek().copied()
+    }
+
+    /// Consumes and returns the next run of up to `max` values that share
+    /// the same `(rep, def)` pair, along with its length. A run is at least
+    /// 1 value long; it is shorter than `max` as soon as the underlying
+    /// rep/def decoders stop producing that constant pair. This lets
+    /// `extend_offsets2` apply the long constant spans that are typical of
+    /// flat or fully-valid repeated columns in one batched step rather than
+    /// one value at a time.
+    fn next_run(&mut self, max: usize) -> PolarsResult<(u32, u32, usize)> {
+        let Some((rep, def)) = self.iter.next() else {
+            polars_bail!(ComputeError: "cannot read rep/def levels")
+        };
+
+        let mut n = 1;
+        while n < max {
+            match self.iter.peek() {
+                Some(&(r, d)) if r == rep && d == def => {
+                    self.iter.next();
+                    n += 1;
+                },
+                _ => break,
+            }
+        }
+        Ok((rep, def, n))
+    }
 }
 
 /// The state of nested data types.
@@ -353,6 +513,35 @@ impl NestedState {
     }
 }
 
+/// Row-range selection pushdown state, threaded through [`next`], [`extend`]
+/// and `extend_offsets2` as a single unit rather than as separate
+/// parameters. `ranges` is a sorted queue of row ranges (relative to the
+/// whole column, not just the current page) to keep; rows outside of it are
+/// decoded from the value stream but dropped instead of being pushed into
+/// `items`. It is `None` when no selection is active. `current_row` is the
+/// absolute index, relative to the column, of the next row to be read, and
+/// is advanced in place as rows are consumed so the next call (possibly over
+/// a different page) can resume the selection at the right offset.
+/// `row_selected` mirrors whether the row `current_row` is pointing at is
+/// kept or dropped; it is carried across calls the same way so a row whose
+/// values span two pages is not re-evaluated (and potentially flipped)
+/// partway through.
+pub(super) struct RowSelection<'b> {
+    pub ranges: Option<&'b mut VecDeque<Range<usize>>>,
+    pub current_row: &'b mut usize,
+    pub row_selected: &'b mut bool,
+}
+
+/// Per-depth cumulative `rep`/`def` thresholds used by `extend_offsets2` to
+/// tell which nesting depths a given (rep, def) pair touches. Reused as scratch
+/// space across every `extend_offsets2` call in one [`extend`] loop (one per
+/// item/page) instead of being reallocated each time.
+#[derive(Default)]
+pub(super) struct OffsetScratch {
+    cum_sum: Vec<u32>,
+    cum_rep: Vec<u32>,
+}
+
 /// Extends `items` by consuming `page`, first trying to complete the last `item`
 /// and extending it if more are needed.
 ///
@@ -361,6 +550,7 @@ impl NestedState {
 /// reading. It therefore returns a bool indicating:
 /// * true  : the row is fully read
 /// * false : the row may not be fully read
+#[allow(clippy::too_many_arguments)]
 pub(super) fn extend<'a, D: NestedDecoder<'a>>(
     page: &'a DataPage,
     init: &[InitNested],
@@ -369,6 +559,7 @@ pub(super) fn extend<'a, D: NestedDecoder<'a>>(
     remaining: &mut usize,
     decoder: &D,
     chunk_size: Option<usize>,
+    selection: &mut RowSelection<'_>,
 ) -> PolarsResult<bool> {
     let mut values_page = decoder.build_state(page, dict)?;
     let mut page = NestedPage::try_new(page)?;
@@ -381,8 +572,7 @@ pub(super) fn extend<'a, D: NestedDecoder<'a>>(
     let chunk_size = chunk_size.unwrap_or(usize::MAX);
     let mut first_item_is_fully_read = false;
     // Amortize the allocations.
-    let mut cum_sum = vec![];
-    let mut cum_rep = vec![];
+    let mut scratch = OffsetScratch::default();
 
     loop {
         if let Some((mut nested, mut decoded)) = items.pop_back() {
@@ -396,8 +586,8 @@ pub(super) fn extend<'a, D: NestedDecoder<'a>>(
                 &mut decoded,
                 decoder,
                 additional,
-                &mut cum_sum,
-                &mut cum_rep,
+                &mut scratch,
+                selection,
             )?;
             first_item_is_fully_read |= is_fully_read;
             *remaining -= nested.len() - existing;
@@ -424,6 +614,43 @@ pub(super) fn extend<'a, D: NestedDecoder<'a>>(
     Ok(first_item_is_fully_read)
 }
 
+/// Drops ranges of `selected_rows` that end at or before `current_row`, then
+/// reports whether `current_row` falls in what's left. Called only when a
+/// new row starts (`rep == 0`); the result is persisted by the caller across
+/// calls so a row whose values span more than one page keeps the selection
+/// state computed at its first `rep == 0`, instead of it being recomputed
+/// (and possibly flipped) for the continuation runs.
+fn recompute_row_selected(selected_rows: &mut VecDeque<Range<usize>>, current_row: usize) -> bool {
+    while selected_rows
+        .front()
+        .is_some_and(|range| range.end <= current_row)
+    {
+        selected_rows.pop_front();
+    }
+    selected_rows
+        .front()
+        .is_some_and(|range| range.contains(&current_row))
+}
+
+/// How many values of the next run `extend_offsets2` may take in one
+/// [`NestedPage::next_run`] call, given the next value's `peek_rep`. Returns
+/// `None` once `additional` rows have already been produced, signalling the
+/// caller to stop.
+fn max_run_at_row_boundary(
+    peek_rep: u32,
+    rows: usize,
+    additional: usize,
+    selecting: bool,
+) -> Option<usize> {
+    if peek_rep != 0 {
+        return Some(usize::MAX);
+    }
+    if rows == additional {
+        return None;
+    }
+    Some(if selecting { 1 } else { additional - rows })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn extend_offsets2<'a, D: NestedDecoder<'a>>(
     page: &mut NestedPage<'a>,
@@ -432,11 +659,11 @@ fn extend_offsets2<'a, D: NestedDecoder<'a>>(
     decoded: &mut D::DecodedState,
     decoder: &D,
     additional: usize,
-    // Amortized allocations
-    cum_sum: &mut Vec<u32>,
-    cum_rep: &mut Vec<u32>,
+    scratch: &mut OffsetScratch,
+    selection: &mut RowSelection<'_>,
 ) -> PolarsResult<bool> {
     let max_depth = nested.len();
+    let OffsetScratch { cum_sum, cum_rep } = scratch;
 
     cum_sum.resize(max_depth + 1, 0);
     cum_rep.resize(max_depth + 1, 0);
@@ -454,45 +681,107 @@ fn extend_offsets2<'a, D: NestedDecoder<'a>>(
         }
     }
 
+    // Amortized: whether a given depth is touched at all for the current
+    // (rep, def) pair, i.e. whether `nest.push`/`push_n` runs for it. Purely
+    // a function of (rep, def) and the static `is_nullable`/`is_required`
+    // flags, so it is identical for every row of a constant run and is
+    // (re)computed once per run rather than once per row.
+    let mut triggered = vec![false; max_depth];
+
     let mut rows = 0;
     loop {
-        // SAFETY: page.iter is always non-empty on first loop.
+        // SAFETY: page is always non-empty on first loop.
         // The current function gets called multiple times with iterators that
         // yield batches of pages. This means e.g. it could be that the very
         // first page is a new row, and the existing nested state has already
         // contains all data from the additional rows.
-        if page.iter.peek().unwrap().0 == 0 {
-            if rows == additional {
-                return Ok(true);
-            }
-            rows += 1;
-        }
+        let (peek_rep, _) = page.peek().unwrap();
+
+        // A run can only be taken in bulk while it stays on one side of a row
+        // boundary: `rep == 0` means every value in the run starts a new row,
+        // so such a run must also be capped by the rows still wanted, and, if
+        // a row selection is active, to a single row at a time so it can be
+        // re-evaluated between rows. Otherwise (`rep != 0`, i.e. values
+        // inside an already-started row) the run cannot cross a row boundary
+        // by construction, so it may be taken in full.
+        let Some(max_run) =
+            max_run_at_row_boundary(peek_rep, rows, additional, selection.ranges.is_some())
+        else {
+            return Ok(true);
+        };
 
         // The errors of the FallibleIterators use in this zipped not checked yet.
         // If one of them errors, the iterator returns None, and this `unwrap` will panic.
-        let Some((rep, def)) = page.iter.next() else {
-            polars_bail!(ComputeError: "cannot read rep/def levels")
-        };
+        let (rep, def, run) = page.next_run(max_run)?;
+
+        if rep == 0 {
+            rows += run;
+
+            if let Some(ranges) = selection.ranges.as_deref_mut() {
+                debug_assert_eq!(run, 1);
+                *selection.row_selected = recompute_row_selected(ranges, *selection.current_row);
+            }
+            *selection.current_row += run;
+        }
 
         let mut is_required = false;
 
         // SAFETY: only bound check elision.
         unsafe {
+            // First pass: figure out, for this (rep, def) pair, which depths
+            // are touched at all — needed below to know whether a depth's
+            // child actually grows every row of the run (`step`) or stays put
+            // (e.g. a required field nested under a level that this run never
+            // enters).
+            let mut is_req = false;
+            for depth in 0..max_depth {
+                let right_level = rep <= *cum_rep.get_unchecked_release(depth)
+                    && def >= *cum_sum.get_unchecked_release(depth);
+                let depth_triggered = is_req || right_level;
+                *triggered.get_unchecked_release_mut(depth) = depth_triggered;
+                if depth_triggered {
+                    let nest = nested.get_unchecked_release(depth);
+                    let is_valid =
+                        nest.is_nullable() && def > *cum_sum.get_unchecked_release(depth);
+                    is_req = nest.is_required() && !is_valid;
+                }
+            }
+
             for depth in 0..max_depth {
                 let right_level = rep <= *cum_rep.get_unchecked_release(depth)
                     && def >= *cum_sum.get_unchecked_release(depth);
                 if is_required || right_level {
-                    let length = nested
-                        .get(depth + 1)
-                        .map(|x| x.len() as i64)
+                    // the child's contribution to each row pushed in this run;
+                    // constant because whether a depth is touched at all does
+                    // not depend on which row of the run we are at (see the
+                    // `triggered` pass above).
+                    let (start, step) = match nested.get(depth + 1) {
+                        Some(child) => {
+                            // offsets index by the child's row count, same unit
+                            // as `child.len()`, regardless of what the child's
+                            // rows are made of (incl. a fixed stride).
+                            let step = if *triggered.get_unchecked_release(depth + 1) {
+                                1
+                            } else {
+                                0
+                            };
+                            (child.len() as i64, step)
+                        },
                         // the last depth is the leaf, which is always increased by 1
-                        .unwrap_or(1);
+                        None => (1, 0),
+                    };
 
                     let nest = nested.get_unchecked_release_mut(depth);
 
                     let is_valid =
                         nest.is_nullable() && def > *cum_sum.get_unchecked_release(depth);
-                    nest.push(length, is_valid);
+                    if *selection.row_selected {
+                        if run == 1 {
+                            nest.push(start, is_valid);
+                        } else {
+                            nest.push_n(start, step, is_valid, run);
+                        }
+                    }
                     is_required = nest.is_required() && !is_valid;
 
                     if depth == max_depth - 1 {
@@ -500,22 +789,37 @@ fn extend_offsets2<'a, D: NestedDecoder<'a>>(
                         let is_valid =
                             (def != *cum_sum.get_unchecked_release(depth)) || !nest.is_nullable();
                         if right_level && is_valid {
-                            decoder.push_valid(values_state, decoded)?;
-                        } else {
-                            decoder.push_null(decoded);
+                            if *selection.row_selected {
+                                if run == 1 {
+                                    decoder.push_valid(values_state, decoded)?;
+                                } else {
+                                    decoder.push_valid_n(values_state, decoded, run)?;
+                                }
+                            } else {
+                                // row is not selected: still consume the values
+                                // so the value stream stays aligned, but drop them.
+                                decoder.skip_valid_n(values_state, run)?;
+                            }
+                        } else if *selection.row_selected {
+                            if run == 1 {
+                                decoder.push_null(decoded);
+                            } else {
+                                decoder.push_null_n(decoded, run);
+                            }
                         }
                     }
                 }
             }
         }
 
-        if page.iter.len() == 0 {
+        if page.len() == 0 {
             return Ok(false);
         }
     }
 }
 
 #[inline]
+#[allow(clippy::too_many_arguments)]
 pub(super) fn next<'a, I, D>(
     iter: &'a mut I,
     items: &mut VecDeque<(NestedState, D::DecodedState)>,
@@ -524,6 +828,7 @@ pub(super) fn next<'a, I, D>(
     init: &[InitNested],
     chunk_size: Option<usize>,
     decoder: &D,
+    selection: &mut RowSelection<'_>,
 ) -> MaybeNext<PolarsResult<(NestedState, D::DecodedState)>>
 where
     I: PagesIter,
@@ -561,6 +866,7 @@ where
                 remaining,
                 decoder,
                 chunk_size,
+                selection,
             );
 
             match is_fully_read {
@@ -575,3 +881,95 @@ where
 /// Type def for a sharable, boxed dyn [`Iterator`] of NestedStates and arrays
 pub type NestedArrayIter<'a> =
     Box<dyn Iterator<Item = PolarsResult<(NestedState, Box<dyn Array>)>> + Send + Sync + 'a>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_fixed_size_push_tracks_length_and_validity() {
+        let mut nested = NestedFixedSize::new(true, 3, 0);
+
+        nested.push(0, true);
+        nested.push(0, false);
+
+        assert_eq!(nested.len(), 2);
+        assert_eq!(nested.num_values(), 6);
+        let (offsets, validity) = nested.inner();
+        // FixedSizeList has constant stride: no offsets buffer at all.
+        assert!(offsets.is_empty());
+        assert_eq!(validity.unwrap().into_iter().collect::<Vec<_>>(), vec![
+            true, false
+        ]);
+    }
+
+    #[test]
+    fn nested_fixed_size_push_n_matches_repeated_push() {
+        let mut via_push_n = NestedFixedSize::new(true, 4, 0);
+        via_push_n.push_n(0, 0, true, 3);
+
+        let mut via_push = NestedFixedSize::new(true, 4, 0);
+        via_push.push(0, true);
+        via_push.push(0, true);
+        via_push.push(0, true);
+
+        assert_eq!(via_push_n.len(), via_push.len());
+        assert_eq!(via_push_n.num_values(), via_push.num_values());
+        assert_eq!(
+            via_push_n.inner().1.unwrap().into_iter().collect::<Vec<_>>(),
+            via_push.inner().1.unwrap().into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn recompute_row_selected_tracks_current_row() {
+        let mut selected_rows = VecDeque::from([0..2, 5..6]);
+
+        // rows 0 and 1 are selected...
+        assert!(recompute_row_selected(&mut selected_rows, 0));
+        assert!(recompute_row_selected(&mut selected_rows, 1));
+        // ...row 2 is not (it falls in the gap before the next range)...
+        assert!(!recompute_row_selected(&mut selected_rows, 2));
+        // ...rows 3 and 4 stay excluded...
+        assert!(!recompute_row_selected(&mut selected_rows, 3));
+        assert!(!recompute_row_selected(&mut selected_rows, 4));
+        // ...and row 5 is selected again once the queue catches up to it.
+        assert!(recompute_row_selected(&mut selected_rows, 5));
+    }
+
+    #[test]
+    fn recompute_row_selected_excludes_past_last_range() {
+        let mut selected_rows = VecDeque::from([0..1]);
+
+        assert!(recompute_row_selected(&mut selected_rows, 0));
+        // once the only range is exhausted, every later row is excluded.
+        assert!(!recompute_row_selected(&mut selected_rows, 1));
+        assert!(!recompute_row_selected(&mut selected_rows, 2));
+        assert!(selected_rows.is_empty());
+    }
+
+    #[test]
+    fn max_run_at_row_boundary_mid_row_is_unbounded() {
+        // rep != 0: still inside an already-started row, can't cross a
+        // row boundary, so it's never capped.
+        assert_eq!(max_run_at_row_boundary(1, 0, 1, false), Some(usize::MAX));
+        assert_eq!(max_run_at_row_boundary(1, 0, 1, true), Some(usize::MAX));
+    }
+
+    #[test]
+    fn max_run_at_row_boundary_stops_once_additional_is_met() {
+        assert_eq!(max_run_at_row_boundary(0, 3, 3, false), None);
+    }
+
+    #[test]
+    fn max_run_at_row_boundary_caps_to_one_row_when_selecting() {
+        // with a row selection active, a rep == 0 run must stop at one row
+        // so it can be re-evaluated against `selected_rows` in between.
+        assert_eq!(max_run_at_row_boundary(0, 1, 5, true), Some(1));
+    }
+
+    #[test]
+    fn max_run_at_row_boundary_takes_remaining_rows_without_selection() {
+        assert_eq!(max_run_at_row_boundary(0, 1, 5, false), Some(4));
+    }
+}